@@ -1,7 +1,114 @@
 use std::vec::Vec;
 use std::mem;
 use std::ptr;
-use std::io::{Read, Result};
+use std::slice;
+use std::cmp;
+use std::fmt;
+use std::error;
+use std::string::FromUtf8Error;
+use std::io::{Read, Write, Result, Error, ErrorKind, SeekFrom};
+
+/// Reads an integer out of a byte slice, asserting the slice is large enough
+macro_rules! read_num_bytes {
+	($ty:ty, $size:expr, $src:expr, $which:ident) => ({
+		assert!($size == mem::size_of::<$ty>());
+		assert!($size <= $src.len());
+		let mut data: $ty = 0;
+		unsafe {
+			ptr::copy_nonoverlapping(
+				$src.as_ptr(),
+				&mut data as *mut $ty as *mut u8,
+				$size);
+		}
+		<$ty>::$which(data)
+	});
+}
+
+/// Writes an integer into a byte slice, asserting the slice is large enough
+macro_rules! write_num_bytes {
+	($ty:ty, $size:expr, $n:expr, $dst:expr, $which:ident) => ({
+		assert!($size <= $dst.len());
+		unsafe {
+			let bytes = mem::transmute::<$ty, [u8; $size]>($n.$which());
+			ptr::copy_nonoverlapping((&bytes).as_ptr(), $dst.as_mut_ptr(), $size);
+		}
+	});
+}
+
+/// Describes how multi-byte integers are laid out in a byte slice
+pub trait ByteOrder {
+	fn read_u16(buf: &[u8]) -> u16;
+	fn read_u32(buf: &[u8]) -> u32;
+	fn read_u64(buf: &[u8]) -> u64;
+	fn write_u16(buf: &mut [u8], n: u16);
+	fn write_u32(buf: &mut [u8], n: u32);
+	fn write_u64(buf: &mut [u8], n: u64);
+}
+
+/// Most significant byte first (network byte order)
+#[derive(Debug)]
+pub enum BigEndian {}
+
+/// Least significant byte first
+#[derive(Debug)]
+pub enum LittleEndian {}
+
+impl ByteOrder for BigEndian {
+	fn read_u16(buf: &[u8]) -> u16 { read_num_bytes!(u16, 2, buf, from_be) }
+	fn read_u32(buf: &[u8]) -> u32 { read_num_bytes!(u32, 4, buf, from_be) }
+	fn read_u64(buf: &[u8]) -> u64 { read_num_bytes!(u64, 8, buf, from_be) }
+	fn write_u16(buf: &mut [u8], n: u16) { write_num_bytes!(u16, 2, n, buf, to_be) }
+	fn write_u32(buf: &mut [u8], n: u32) { write_num_bytes!(u32, 4, n, buf, to_be) }
+	fn write_u64(buf: &mut [u8], n: u64) { write_num_bytes!(u64, 8, n, buf, to_be) }
+}
+
+impl ByteOrder for LittleEndian {
+	fn read_u16(buf: &[u8]) -> u16 { read_num_bytes!(u16, 2, buf, from_le) }
+	fn read_u32(buf: &[u8]) -> u32 { read_num_bytes!(u32, 4, buf, from_le) }
+	fn read_u64(buf: &[u8]) -> u64 { read_num_bytes!(u64, 8, buf, from_le) }
+	fn write_u16(buf: &mut [u8], n: u16) { write_num_bytes!(u16, 2, n, buf, to_le) }
+	fn write_u32(buf: &mut [u8], n: u32) { write_num_bytes!(u32, 4, n, buf, to_le) }
+	fn write_u64(buf: &mut [u8], n: u64) { write_num_bytes!(u64, 8, n, buf, to_le) }
+}
+
+/// The endianness of the target platform
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+
+/// The endianness of the target platform
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+/// Errors produced by the fallible `try_*` accessors
+#[derive(Debug)]
+pub enum BufferError {
+	/// Not enough readable bytes to satisfy the request
+	Eof,
+	/// The requested offset or length falls outside the buffer's bounds
+	OutOfRange,
+	/// The retrieved bytes were not valid UTF-8
+	Utf8(FromUtf8Error),
+}
+
+impl fmt::Display for BufferError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			BufferError::Eof => write!(f, "not enough bytes in buffer"),
+			BufferError::OutOfRange => write!(f, "offset out of range"),
+			BufferError::Utf8(ref e) => write!(f, "invalid utf8: {}", e),
+		}
+	}
+}
+
+impl error::Error for BufferError {
+	fn description(&self) -> &str {
+		match *self {
+			BufferError::Eof => "not enough bytes in buffer",
+			BufferError::OutOfRange => "offset out of range",
+			BufferError::Utf8(_) => "invalid utf8",
+		}
+	}
+}
 
 /// A byte buffer class modeled after muduo::net::Buffer
 ///
@@ -13,6 +120,108 @@ use std::io::{Read, Result};
 /// 0      <=      readerIndex   <=   writerIndex    <=     size
 ///
 
+/// Reads an integer out of a byte slice, asserting the slice is large enough
+macro_rules! read_num_bytes {
+	($ty:ty, $size:expr, $src:expr, $which:ident) => ({
+		assert!($size == mem::size_of::<$ty>());
+		assert!($size <= $src.len());
+		let mut data: $ty = 0;
+		unsafe {
+			ptr::copy_nonoverlapping(
+				$src.as_ptr(),
+				&mut data as *mut $ty as *mut u8,
+				$size);
+		}
+		<$ty>::$which(data)
+	});
+}
+
+/// Writes an integer into a byte slice, asserting the slice is large enough
+macro_rules! write_num_bytes {
+	($ty:ty, $size:expr, $n:expr, $dst:expr, $which:ident) => ({
+		assert!($size <= $dst.len());
+		unsafe {
+			let bytes = mem::transmute::<$ty, [u8; $size]>($n.$which());
+			ptr::copy_nonoverlapping((&bytes).as_ptr(), $dst.as_mut_ptr(), $size);
+		}
+	});
+}
+
+/// Describes how multi-byte integers are laid out in a byte slice
+pub trait ByteOrder {
+	fn read_u16(buf: &[u8]) -> u16;
+	fn read_u32(buf: &[u8]) -> u32;
+	fn read_u64(buf: &[u8]) -> u64;
+	fn write_u16(buf: &mut [u8], n: u16);
+	fn write_u32(buf: &mut [u8], n: u32);
+	fn write_u64(buf: &mut [u8], n: u64);
+}
+
+/// Most significant byte first (network byte order)
+#[derive(Debug)]
+pub enum BigEndian {}
+
+/// Least significant byte first
+#[derive(Debug)]
+pub enum LittleEndian {}
+
+impl ByteOrder for BigEndian {
+	fn read_u16(buf: &[u8]) -> u16 { read_num_bytes!(u16, 2, buf, from_be) }
+	fn read_u32(buf: &[u8]) -> u32 { read_num_bytes!(u32, 4, buf, from_be) }
+	fn read_u64(buf: &[u8]) -> u64 { read_num_bytes!(u64, 8, buf, from_be) }
+	fn write_u16(buf: &mut [u8], n: u16) { write_num_bytes!(u16, 2, n, buf, to_be) }
+	fn write_u32(buf: &mut [u8], n: u32) { write_num_bytes!(u32, 4, n, buf, to_be) }
+	fn write_u64(buf: &mut [u8], n: u64) { write_num_bytes!(u64, 8, n, buf, to_be) }
+}
+
+impl ByteOrder for LittleEndian {
+	fn read_u16(buf: &[u8]) -> u16 { read_num_bytes!(u16, 2, buf, from_le) }
+	fn read_u32(buf: &[u8]) -> u32 { read_num_bytes!(u32, 4, buf, from_le) }
+	fn read_u64(buf: &[u8]) -> u64 { read_num_bytes!(u64, 8, buf, from_le) }
+	fn write_u16(buf: &mut [u8], n: u16) { write_num_bytes!(u16, 2, n, buf, to_le) }
+	fn write_u32(buf: &mut [u8], n: u32) { write_num_bytes!(u32, 4, n, buf, to_le) }
+	fn write_u64(buf: &mut [u8], n: u64) { write_num_bytes!(u64, 8, n, buf, to_le) }
+}
+
+/// The endianness of the target platform
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+
+/// The endianness of the target platform
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+/// Errors produced by the fallible `try_*` accessors
+#[derive(Debug)]
+pub enum BufferError {
+	/// Not enough readable bytes to satisfy the request
+	Eof,
+	/// The requested offset or length falls outside the buffer's bounds
+	OutOfRange,
+	/// The retrieved bytes were not valid UTF-8
+	Utf8(FromUtf8Error),
+}
+
+impl fmt::Display for BufferError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			BufferError::Eof => write!(f, "not enough bytes in buffer"),
+			BufferError::OutOfRange => write!(f, "offset out of range"),
+			BufferError::Utf8(ref e) => write!(f, "invalid utf8: {}", e),
+		}
+	}
+}
+
+impl error::Error for BufferError {
+	fn description(&self) -> &str {
+		match *self {
+			BufferError::Eof => "not enough bytes in buffer",
+			BufferError::OutOfRange => "offset out of range",
+			BufferError::Utf8(_) => "invalid utf8",
+		}
+	}
+}
+
 #[derive(Debug)]
 pub struct Buffer {
 	read_index: 	usize,
@@ -115,42 +324,46 @@ impl Buffer {
 	/// Peeks one byte in buffer
 	pub fn peek(&self) -> *const u8 { &self.data[self.read_index] }
 	
-	/// Peeks an int64 in buffer
-	pub fn peek_int64(&self) -> i64 {
+	/// Peeks an int64 in buffer using the given byte order
+	pub fn peek_int64_as<BO: ByteOrder>(&self) -> i64 {
 		assert!(self.readable_bytes() >= mem::size_of::<i64>());
-		let mut bytes: [u8; 8] = [0u8; 8];
-		let be64: i64;
-		unsafe { 
-			ptr::copy_nonoverlapping(self.peek(), &mut bytes[0], mem::size_of::<i64>());
-			be64 = mem::transmute::<[u8; 8], i64>(bytes);
+		let bytes = unsafe { slice::from_raw_parts(self.peek(), mem::size_of::<i64>()) };
+		BO::read_u64(bytes) as i64
+	}
+
+	/// Peeks an int64 in buffer, returning an error instead of panicking
+	pub fn try_peek_int64(&self) -> ::std::result::Result<i64, BufferError> {
+		if self.readable_bytes() < mem::size_of::<i64>() {
+			return Err(BufferError::Eof);
 		}
-		i64::from_be(be64)
+		Ok(self.peek_int64_as::<BigEndian>())
 	}
-	
-	/// Peeks an int32 in buffer
-	pub fn peek_int32(&self) -> i32 {
+
+	/// Peeks an int64 in buffer
+	pub fn peek_int64(&self) -> i64 {
+		self.try_peek_int64().expect("peek_int64: not enough readable bytes")
+	}
+
+	/// Peeks an int32 in buffer using the given byte order
+	pub fn peek_int32_as<BO: ByteOrder>(&self) -> i32 {
 		assert!(self.readable_bytes() >= mem::size_of::<i32>());
-		let mut bytes: [u8; 4] = [0u8; 4];
-		let be32: i32;
-		unsafe {
-			ptr::copy_nonoverlapping(self.peek(), &mut bytes[0], mem::size_of::<i32>());
-			be32 = mem::transmute::<[u8; 4], i32>(bytes);
-		}
-		i32::from_be(be32)
+		let bytes = unsafe { slice::from_raw_parts(self.peek(), mem::size_of::<i32>()) };
+		BO::read_u32(bytes) as i32
 	}
-	
-	/// Peeks an int16 in buffer
-	pub fn peek_int16(&self) -> i16 {
+
+	/// Peeks an int32 in buffer
+	pub fn peek_int32(&self) -> i32 { self.peek_int32_as::<BigEndian>() }
+
+	/// Peeks an int16 in buffer using the given byte order
+	pub fn peek_int16_as<BO: ByteOrder>(&self) -> i16 {
 		assert!(self.readable_bytes() >= mem::size_of::<i16>());
-		let mut bytes: [u8; 2] = [0u8; 2];
-		let be16: i16;
-		unsafe { 
-			ptr::copy_nonoverlapping(self.peek(), &mut bytes[0], mem::size_of::<i16>());
-			be16 = mem::transmute::<[u8; 2], i16>(bytes);
-		}
-		i16::from_be(be16)
+		let bytes = unsafe { slice::from_raw_parts(self.peek(), mem::size_of::<i16>()) };
+		BO::read_u16(bytes) as i16
 	}
-	
+
+	/// Peeks an int16 in buffer
+	pub fn peek_int16(&self) -> i16 { self.peek_int16_as::<BigEndian>() }
+
 	/// Peeks an int8 in buffer
 	pub fn peek_int8(&self) -> i8 {
 		assert!(self.readable_bytes() >= mem::size_of::<i8>());
@@ -158,7 +371,59 @@ impl Buffer {
 		unsafe { be8 = *self.peek() as i8; }
 		i8::from_be(be8)
 	}
-	
+
+	/// Peeks a uint64 in buffer using the given byte order
+	pub fn peek_uint64_as<BO: ByteOrder>(&self) -> u64 {
+		assert!(self.readable_bytes() >= mem::size_of::<u64>());
+		let bytes = unsafe { slice::from_raw_parts(self.peek(), mem::size_of::<u64>()) };
+		BO::read_u64(bytes)
+	}
+
+	/// Peeks a uint64 in buffer
+	pub fn peek_uint64(&self) -> u64 { self.peek_uint64_as::<BigEndian>() }
+
+	/// Peeks a uint32 in buffer using the given byte order
+	pub fn peek_uint32_as<BO: ByteOrder>(&self) -> u32 {
+		assert!(self.readable_bytes() >= mem::size_of::<u32>());
+		let bytes = unsafe { slice::from_raw_parts(self.peek(), mem::size_of::<u32>()) };
+		BO::read_u32(bytes)
+	}
+
+	/// Peeks a uint32 in buffer
+	pub fn peek_uint32(&self) -> u32 { self.peek_uint32_as::<BigEndian>() }
+
+	/// Peeks a uint16 in buffer using the given byte order
+	pub fn peek_uint16_as<BO: ByteOrder>(&self) -> u16 {
+		assert!(self.readable_bytes() >= mem::size_of::<u16>());
+		let bytes = unsafe { slice::from_raw_parts(self.peek(), mem::size_of::<u16>()) };
+		BO::read_u16(bytes)
+	}
+
+	/// Peeks a uint16 in buffer
+	pub fn peek_uint16(&self) -> u16 { self.peek_uint16_as::<BigEndian>() }
+
+	/// Peeks a uint8 in buffer
+	pub fn peek_uint8(&self) -> u8 {
+		assert!(self.readable_bytes() >= mem::size_of::<u8>());
+		unsafe { *self.peek() }
+	}
+
+	/// Peeks a float32 in buffer using the given byte order
+	pub fn peek_float32_as<BO: ByteOrder>(&self) -> f32 {
+		f32::from_bits(self.peek_uint32_as::<BO>())
+	}
+
+	/// Peeks a float32 in buffer
+	pub fn peek_float32(&self) -> f32 { self.peek_float32_as::<BigEndian>() }
+
+	/// Peeks a float64 in buffer using the given byte order
+	pub fn peek_float64_as<BO: ByteOrder>(&self) -> f64 {
+		f64::from_bits(self.peek_uint64_as::<BO>())
+	}
+
+	/// Peeks a float64 in buffer
+	pub fn peek_float64(&self) -> f64 { self.peek_float64_as::<BigEndian>() }
+
 	/// Appends bytes in buffer
 	pub fn append_bytes(&mut self, bytes: &[u8]) {
 		self.ensure_writable_bytes(bytes.len());
@@ -169,32 +434,35 @@ impl Buffer {
 	/// Appends a string in buffer
 	pub fn append_string(&mut self, str: &String) { self.append_bytes(str.as_bytes()); }
 	
+	/// Appends int64 in buffer using the given byte order
+	pub fn append_int64_as<BO: ByteOrder>(&mut self, x: i64) {
+		let mut bytes = [0u8; 8];
+		BO::write_u64(&mut bytes, x as u64);
+		self.append_bytes(&bytes);
+	}
+
 	/// Appends int64 using network endian
-	pub fn append_int64(&mut self, x: i64) {
-		let be64 = x.to_be();
-		let bytes: [u8; 8] = unsafe {
-			mem::transmute::<i64, [u8; 8]>(be64)
-		};
+	pub fn append_int64(&mut self, x: i64) { self.append_int64_as::<BigEndian>(x) }
+
+	/// Appends int32 in buffer using the given byte order
+	pub fn append_int32_as<BO: ByteOrder>(&mut self, x: i32) {
+		let mut bytes = [0u8; 4];
+		BO::write_u32(&mut bytes, x as u32);
 		self.append_bytes(&bytes);
 	}
-	
+
 	/// Appends int32 using network endian
-	pub fn append_int32(&mut self, x: i32) {
-		let be32 = x.to_be();
-		let bytes: [u8; 4] = unsafe {
-			mem::transmute::<i32, [u8; 4]>(be32)
-		};
+	pub fn append_int32(&mut self, x: i32) { self.append_int32_as::<BigEndian>(x) }
+
+	/// Appends int16 in buffer using the given byte order
+	pub fn append_int16_as<BO: ByteOrder>(&mut self, x: i16) {
+		let mut bytes = [0u8; 2];
+		BO::write_u16(&mut bytes, x as u16);
 		self.append_bytes(&bytes);
 	}
-	
+
 	/// Appends int16 using network endian
-	pub fn append_int16(&mut self, x: i16) {
-		let be16 = x.to_be();
-		let bytes: [u8; 2] = unsafe {
-			mem::transmute::<i16, [u8; 2]>(be16)
-		};
-		self.append_bytes(&bytes);
-	}
+	pub fn append_int16(&mut self, x: i16) { self.append_int16_as::<BigEndian>(x) }
 	
 	/// Appends int8 in buffer
 	pub fn append_int8(&mut self, x: i8) {
@@ -203,7 +471,52 @@ impl Buffer {
 		};
 		self.append_bytes(&bytes);
 	}
-	
+
+	/// Appends uint64 in buffer using the given byte order
+	pub fn append_uint64_as<BO: ByteOrder>(&mut self, x: u64) {
+		let mut bytes = [0u8; 8];
+		BO::write_u64(&mut bytes, x);
+		self.append_bytes(&bytes);
+	}
+
+	/// Appends uint64 using network endian
+	pub fn append_uint64(&mut self, x: u64) { self.append_uint64_as::<BigEndian>(x) }
+
+	/// Appends uint32 in buffer using the given byte order
+	pub fn append_uint32_as<BO: ByteOrder>(&mut self, x: u32) {
+		let mut bytes = [0u8; 4];
+		BO::write_u32(&mut bytes, x);
+		self.append_bytes(&bytes);
+	}
+
+	/// Appends uint32 using network endian
+	pub fn append_uint32(&mut self, x: u32) { self.append_uint32_as::<BigEndian>(x) }
+
+	/// Appends uint16 in buffer using the given byte order
+	pub fn append_uint16_as<BO: ByteOrder>(&mut self, x: u16) {
+		let mut bytes = [0u8; 2];
+		BO::write_u16(&mut bytes, x);
+		self.append_bytes(&bytes);
+	}
+
+	/// Appends uint16 using network endian
+	pub fn append_uint16(&mut self, x: u16) { self.append_uint16_as::<BigEndian>(x) }
+
+	/// Appends uint8 in buffer
+	pub fn append_uint8(&mut self, x: u8) { self.append_bytes(&[x]); }
+
+	/// Appends float32 in buffer using the given byte order
+	pub fn append_float32_as<BO: ByteOrder>(&mut self, x: f32) { self.append_uint32_as::<BO>(x.to_bits()) }
+
+	/// Appends float32 using network endian
+	pub fn append_float32(&mut self, x: f32) { self.append_float32_as::<BigEndian>(x) }
+
+	/// Appends float64 in buffer using the given byte order
+	pub fn append_float64_as<BO: ByteOrder>(&mut self, x: f64) { self.append_uint64_as::<BO>(x.to_bits()) }
+
+	/// Appends float64 using network endian
+	pub fn append_float64(&mut self, x: f64) { self.append_float64_as::<BigEndian>(x) }
+
 	/// Ensures 'len' bytes space left
 	pub fn ensure_writable_bytes(&mut self, len: usize) {
 		if self.writable_bytes() < len {
@@ -232,23 +545,41 @@ impl Buffer {
 		}
 	}
 	
-	/// Retrieves bytes in buffer as string
-	pub fn retrieve_as_string(&mut self, len: usize) -> String {
-		assert!(len <= self.readable_bytes());
+	/// Retrieves bytes in buffer as string, returning an error instead of panicking/unwrapping
+	pub fn try_retrieve_as_string(&mut self, len: usize) -> ::std::result::Result<String, BufferError> {
+		if len > self.readable_bytes() {
+			return Err(BufferError::Eof);
+		}
+		if len == 0 {
+			return Ok(String::new());
+		}
 		let mut bytes = vec![0u8; len];
 		unsafe { ptr::copy_nonoverlapping(self.peek(), &mut bytes[0], len); }
 		self.retrieve(len);
-		String::from_utf8(bytes).unwrap()
+		String::from_utf8(bytes).map_err(BufferError::Utf8)
 	}
-	
-	/// Retrieves 'len' bytes in buffer
-	pub fn retrieve(&mut self, len: usize) {
-		assert!(len <= self.readable_bytes());
+
+	/// Retrieves bytes in buffer as string
+	pub fn retrieve_as_string(&mut self, len: usize) -> String {
+		self.try_retrieve_as_string(len).expect("retrieve_as_string: not enough bytes or invalid utf8")
+	}
+
+	/// Retrieves 'len' bytes in buffer, returning an error instead of panicking
+	pub fn try_retrieve(&mut self, len: usize) -> ::std::result::Result<(), BufferError> {
+		if len > self.readable_bytes() {
+			return Err(BufferError::Eof);
+		}
 		if len < self.readable_bytes() {
 			self.read_index += len;
 		} else {
 			self.retrieve_all();
 		}
+		Ok(())
+	}
+
+	/// Retrieves 'len' bytes in buffer
+	pub fn retrieve(&mut self, len: usize) {
+		self.try_retrieve(len).expect("retrieve: not enough readable bytes");
 	}
 	
 	/// Retrieves int64 in buffer
@@ -262,7 +593,19 @@ impl Buffer {
 	
 	/// Retrieves int8 in buffer
 	pub fn retrieve_int8(&mut self) { self.retrieve(mem::size_of::<i8>()) }
-	
+
+	/// Retrieves uint64 in buffer
+	pub fn retrieve_uint64(&mut self) { self.retrieve(mem::size_of::<u64>()) }
+
+	/// Retrieves uint32 in buffer
+	pub fn retrieve_uint32(&mut self) { self.retrieve(mem::size_of::<u32>()) }
+
+	/// Retrieves uint16 in buffer
+	pub fn retrieve_uint16(&mut self) { self.retrieve(mem::size_of::<u16>()) }
+
+	/// Retrieves uint8 in buffer
+	pub fn retrieve_uint8(&mut self) { self.retrieve(mem::size_of::<u8>()) }
+
 	/// Retrieves all bytes in buffer
 	pub fn retrieve_all(&mut self) {
 		self.read_index = PREPEND;
@@ -283,40 +626,93 @@ impl Buffer {
 		self.retrieve_as_string(readable)
 	}
 	
-	/// Prepends bytes in buffer
-	pub fn prepend_bytes(&mut self, bytes: &[u8]) {
-		assert!(bytes.len() <= self.prependable_bytes());
+	/// Prepends bytes in buffer, returning an error instead of panicking
+	pub fn try_prepend_bytes(&mut self, bytes: &[u8]) -> ::std::result::Result<(), BufferError> {
+		if bytes.len() > self.prependable_bytes() {
+			return Err(BufferError::OutOfRange);
+		}
+		if bytes.is_empty() {
+			return Ok(());
+		}
 		self.read_index -= bytes.len();
 		unsafe { ptr::copy_nonoverlapping(&bytes[0], &mut self.data[self.read_index], bytes.len()); }
+		Ok(())
+	}
+
+	/// Prepends bytes in buffer
+	pub fn prepend_bytes(&mut self, bytes: &[u8]) {
+		self.try_prepend_bytes(bytes).expect("prepend_bytes: not enough prependable bytes");
 	}
 	
-	/// Prepends an int64 in buffer using network endian 
-	pub fn prepend_int64(&mut self, x: i64) {
-		let be64 = x.to_be();
-		let bytes: [u8; 8] = unsafe { mem::transmute::<i64, [u8; 8]>(be64) };
+	/// Prepends an int64 in buffer using the given byte order
+	pub fn prepend_int64_as<BO: ByteOrder>(&mut self, x: i64) {
+		let mut bytes = [0u8; 8];
+		BO::write_u64(&mut bytes, x as u64);
 		self.prepend_bytes(&bytes);
 	}
-	
-	/// Prepends an int32 in buffer using network endian 
-	pub fn prepend_int32(&mut self, x: i32) {
-		let be32 = x.to_be();
-		let bytes: [u8; 4] = unsafe { mem::transmute::<i32, [u8; 4]>(be32) };
+
+	/// Prepends an int64 in buffer using network endian
+	pub fn prepend_int64(&mut self, x: i64) { self.prepend_int64_as::<BigEndian>(x) }
+
+	/// Prepends an int32 in buffer using the given byte order
+	pub fn prepend_int32_as<BO: ByteOrder>(&mut self, x: i32) {
+		let mut bytes = [0u8; 4];
+		BO::write_u32(&mut bytes, x as u32);
 		self.prepend_bytes(&bytes);
 	}
-	
-	/// Prepends an int16 in buffer using network endian 
-	pub fn prepend_int16(&mut self, x: i16) {
-		let be16 = x.to_be();
-		let bytes: [u8; 2] = unsafe { mem::transmute::<i16, [u8; 2]>(be16) };
+
+	/// Prepends an int32 in buffer using network endian
+	pub fn prepend_int32(&mut self, x: i32) { self.prepend_int32_as::<BigEndian>(x) }
+
+	/// Prepends an int16 in buffer using the given byte order
+	pub fn prepend_int16_as<BO: ByteOrder>(&mut self, x: i16) {
+		let mut bytes = [0u8; 2];
+		BO::write_u16(&mut bytes, x as u16);
 		self.prepend_bytes(&bytes);
 	}
+
+	/// Prepends an int16 in buffer using network endian
+	pub fn prepend_int16(&mut self, x: i16) { self.prepend_int16_as::<BigEndian>(x) }
 	
 	/// Prepends an int8 in buffer
 	pub fn prepend_int8(&mut self, x: i8) {
 		let bytes: [u8; 1] = unsafe { mem::transmute::<i8, [u8; 1]>(x) };
 		self.prepend_bytes(&bytes);
 	}
-	
+
+	/// Prepends a uint64 in buffer using the given byte order
+	pub fn prepend_uint64_as<BO: ByteOrder>(&mut self, x: u64) {
+		let mut bytes = [0u8; 8];
+		BO::write_u64(&mut bytes, x);
+		self.prepend_bytes(&bytes);
+	}
+
+	/// Prepends a uint64 in buffer using network endian
+	pub fn prepend_uint64(&mut self, x: u64) { self.prepend_uint64_as::<BigEndian>(x) }
+
+	/// Prepends a uint32 in buffer using the given byte order
+	pub fn prepend_uint32_as<BO: ByteOrder>(&mut self, x: u32) {
+		let mut bytes = [0u8; 4];
+		BO::write_u32(&mut bytes, x);
+		self.prepend_bytes(&bytes);
+	}
+
+	/// Prepends a uint32 in buffer using network endian
+	pub fn prepend_uint32(&mut self, x: u32) { self.prepend_uint32_as::<BigEndian>(x) }
+
+	/// Prepends a uint16 in buffer using the given byte order
+	pub fn prepend_uint16_as<BO: ByteOrder>(&mut self, x: u16) {
+		let mut bytes = [0u8; 2];
+		BO::write_u16(&mut bytes, x);
+		self.prepend_bytes(&bytes);
+	}
+
+	/// Prepends a uint16 in buffer using network endian
+	pub fn prepend_uint16(&mut self, x: u16) { self.prepend_uint16_as::<BigEndian>(x) }
+
+	/// Prepends a uint8 in buffer
+	pub fn prepend_uint8(&mut self, x: u8) { self.prepend_bytes(&[x]); }
+
 	pub fn unwrite(&mut self, len: usize) {
 		assert!(len <= self.readable_bytes());
 		self.write_index -= len;
@@ -324,26 +720,45 @@ impl Buffer {
 	
 	pub fn internal_capacity(&self) -> usize { self.data.capacity() }
 	
-	/// Read int64 from network endian
-	pub fn read_int64(&mut self) -> i64 {
-		let ret = self.peek_int64();
+	/// Read int64 using the given byte order
+	pub fn read_int64_as<BO: ByteOrder>(&mut self) -> i64 {
+		let ret = self.peek_int64_as::<BO>();
 		self.retrieve_int64();
 		ret
 	}
-	
-	/// Read int32 from network endian
-	pub fn read_int32(&mut self) -> i32 {
-		let ret = self.peek_int32();
+
+	/// Read int64 from network endian
+	pub fn read_int64(&mut self) -> i64 { self.read_int64_as::<BigEndian>() }
+
+	/// Read int32 using the given byte order
+	pub fn read_int32_as<BO: ByteOrder>(&mut self) -> i32 {
+		let ret = self.peek_int32_as::<BO>();
 		self.retrieve_int32();
 		ret
 	}
-	
-	/// Read int16 from network endian
-	pub fn read_int16(&mut self) -> i16 {
-		let ret = self.peek_int16();
+
+	/// Read int32 from network endian, returning an error instead of panicking
+	pub fn try_read_int32(&mut self) -> ::std::result::Result<i32, BufferError> {
+		if self.readable_bytes() < mem::size_of::<i32>() {
+			return Err(BufferError::Eof);
+		}
+		Ok(self.read_int32_as::<BigEndian>())
+	}
+
+	/// Read int32 from network endian
+	pub fn read_int32(&mut self) -> i32 {
+		self.try_read_int32().expect("read_int32: not enough readable bytes")
+	}
+
+	/// Read int16 using the given byte order
+	pub fn read_int16_as<BO: ByteOrder>(&mut self) -> i16 {
+		let ret = self.peek_int16_as::<BO>();
 		self.retrieve_int16();
 		ret
 	}
+
+	/// Read int16 from network endian
+	pub fn read_int16(&mut self) -> i16 { self.read_int16_as::<BigEndian>() }
 	
 	/// Read int8 from network endian
 	pub fn read_int8(&mut self) -> i8 {
@@ -351,7 +766,64 @@ impl Buffer {
 		self.retrieve_int8();
 		ret
 	}
-	
+
+	/// Read uint64 using the given byte order
+	pub fn read_uint64_as<BO: ByteOrder>(&mut self) -> u64 {
+		let ret = self.peek_uint64_as::<BO>();
+		self.retrieve_uint64();
+		ret
+	}
+
+	/// Read uint64 from network endian
+	pub fn read_uint64(&mut self) -> u64 { self.read_uint64_as::<BigEndian>() }
+
+	/// Read uint32 using the given byte order
+	pub fn read_uint32_as<BO: ByteOrder>(&mut self) -> u32 {
+		let ret = self.peek_uint32_as::<BO>();
+		self.retrieve_uint32();
+		ret
+	}
+
+	/// Read uint32 from network endian
+	pub fn read_uint32(&mut self) -> u32 { self.read_uint32_as::<BigEndian>() }
+
+	/// Read uint16 using the given byte order
+	pub fn read_uint16_as<BO: ByteOrder>(&mut self) -> u16 {
+		let ret = self.peek_uint16_as::<BO>();
+		self.retrieve_uint16();
+		ret
+	}
+
+	/// Read uint16 from network endian
+	pub fn read_uint16(&mut self) -> u16 { self.read_uint16_as::<BigEndian>() }
+
+	/// Read uint8 from network endian
+	pub fn read_uint8(&mut self) -> u8 {
+		let ret = self.peek_uint8();
+		self.retrieve_uint8();
+		ret
+	}
+
+	/// Read float32 using the given byte order
+	pub fn read_float32_as<BO: ByteOrder>(&mut self) -> f32 {
+		let ret = self.peek_float32_as::<BO>();
+		self.retrieve_uint32();
+		ret
+	}
+
+	/// Read float32 from network endian
+	pub fn read_float32(&mut self) -> f32 { self.read_float32_as::<BigEndian>() }
+
+	/// Read float64 using the given byte order
+	pub fn read_float64_as<BO: ByteOrder>(&mut self) -> f64 {
+		let ret = self.peek_float64_as::<BO>();
+		self.retrieve_uint64();
+		ret
+	}
+
+	/// Read float64 from network endian
+	pub fn read_float64(&mut self) -> f64 { self.read_float64_as::<BigEndian>() }
+
 	pub fn begin_write(&mut self) -> *mut u8 { &mut self.data[self.write_index] }
 	
 	/// Reads from stream
@@ -361,6 +833,255 @@ impl Buffer {
 		self.append_bytes(&bytes[..received]);
 		Ok(received)
 	}
+
+	/// Returns an iterator draining the buffer one byte at a time
+	///
+	/// Named distinctly from `std::io::Read::bytes` (which this type also implements but
+	/// which consumes the buffer and yields `Result<u8, Error>`) to avoid shadowing it.
+	pub fn byte_iter(&mut self) -> ByteIterator {
+		ByteIterator { buf: self }
+	}
+
+	/// Returns the read cursor's offset from the start of the content area (index `PREPEND`);
+	/// bytes already retrieved still count until the buffer is fully drained
+	pub fn tell(&self) -> usize { self.read_index - PREPEND }
+
+	/// Repositions the read cursor anywhere between the start of the content area (index
+	/// `PREPEND`) and the write index, so bytes already retrieved can be read again until the
+	/// buffer is fully drained; returns the new offset as `tell` reports it
+	pub fn seek(&mut self, pos: SeekFrom) -> Result<usize> {
+		let end = (self.write_index - PREPEND) as i64;
+		let target = match pos {
+			SeekFrom::Start(offset) => offset as i64,
+			SeekFrom::End(offset) => end + offset,
+			SeekFrom::Current(offset) => self.tell() as i64 + offset,
+		};
+		if target < 0 || target > end {
+			return Err(Error::new(ErrorKind::InvalidInput, "seek position out of range"));
+		}
+		self.read_index = PREPEND + target as usize;
+		Ok(target as usize)
+	}
+
+	/// Peeks an int64 at 'offset' bytes past the read cursor, without consuming it
+	pub fn peek_int64_at(&self, offset: usize) -> i64 {
+		assert!(self.read_index + offset + mem::size_of::<i64>() <= self.write_index);
+		let bytes = unsafe { slice::from_raw_parts(self.data.as_ptr().offset((self.read_index + offset) as isize), mem::size_of::<i64>()) };
+		BigEndian::read_u64(bytes) as i64
+	}
+
+	/// Peeks an int32 at 'offset' bytes past the read cursor, without consuming it
+	pub fn peek_int32_at(&self, offset: usize) -> i32 {
+		assert!(self.read_index + offset + mem::size_of::<i32>() <= self.write_index);
+		let bytes = unsafe { slice::from_raw_parts(self.data.as_ptr().offset((self.read_index + offset) as isize), mem::size_of::<i32>()) };
+		BigEndian::read_u32(bytes) as i32
+	}
+
+	/// Peeks an int16 at 'offset' bytes past the read cursor, without consuming it
+	pub fn peek_int16_at(&self, offset: usize) -> i16 {
+		assert!(self.read_index + offset + mem::size_of::<i16>() <= self.write_index);
+		let bytes = unsafe { slice::from_raw_parts(self.data.as_ptr().offset((self.read_index + offset) as isize), mem::size_of::<i16>()) };
+		BigEndian::read_u16(bytes) as i16
+	}
+
+	/// Peeks an int8 at 'offset' bytes past the read cursor, without consuming it
+	pub fn peek_int8_at(&self, offset: usize) -> i8 {
+		assert!(self.read_index + offset + mem::size_of::<i8>() <= self.write_index);
+		unsafe { *self.data.as_ptr().offset((self.read_index + offset) as isize) as i8 }
+	}
+}
+
+impl Read for Buffer {
+	/// Drains readable bytes from the front of the buffer into 'buf'
+	fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+		let len = cmp::min(buf.len(), self.readable_bytes());
+		unsafe { ptr::copy_nonoverlapping(self.peek(), buf.as_mut_ptr(), len); }
+		self.retrieve(len);
+		Ok(len)
+	}
+}
+
+impl Write for Buffer {
+	/// Appends 'buf' to the writable space, growing the buffer as needed
+	fn write(&mut self, buf: &[u8]) -> Result<usize> {
+		self.append_bytes(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> Result<()> { Ok(()) }
+}
+
+/// Iterator draining a buffer one byte at a time
+pub struct ByteIterator<'a> {
+	buf: &'a mut Buffer,
+}
+
+impl<'a> Iterator for ByteIterator<'a> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		if self.buf.readable_bytes() == 0 {
+			None
+		} else {
+			Some(self.buf.read_uint8())
+		}
+	}
+}
+
+/// A readable view over a buffer, modeled after the `bytes` crate's `Buf` trait.
+///
+/// Implemented by `Buffer` itself and by the `Chain`/`Take` adapters, so the
+/// adapters can be nested (e.g. `a.chain(b).take(10)`) without copying.
+pub trait Buf: Sized {
+	/// Returns how many bytes can still be read from this view
+	fn readable_bytes(&self) -> usize;
+
+	/// Reads a single byte, advancing past it
+	fn read_u8(&mut self) -> u8;
+
+	/// Skips 'len' bytes without returning them
+	fn retrieve(&mut self, len: usize);
+
+	/// Reads an int8
+	fn read_int8(&mut self) -> i8 { self.read_u8() as i8 }
+
+	/// Reads an int16 using network endian
+	fn read_int16(&mut self) -> i16 {
+		let mut x: i16 = 0;
+		for _ in 0..2 { x = (x << 8) | self.read_u8() as i16; }
+		x
+	}
+
+	/// Reads an int32 using network endian
+	fn read_int32(&mut self) -> i32 {
+		let mut x: i32 = 0;
+		for _ in 0..4 { x = (x << 8) | self.read_u8() as i32; }
+		x
+	}
+
+	/// Reads an int64 using network endian
+	fn read_int64(&mut self) -> i64 {
+		let mut x: i64 = 0;
+		for _ in 0..8 { x = (x << 8) | self.read_u8() as i64; }
+		x
+	}
+
+	/// Reads 'len' bytes as a UTF-8 string
+	fn retrieve_as_string(&mut self, len: usize) -> String {
+		let mut bytes = Vec::with_capacity(len);
+		for _ in 0..len { bytes.push(self.read_u8()); }
+		String::from_utf8(bytes).unwrap()
+	}
+
+	/// Chains this view with 'other', reading 'other' once this one is exhausted
+	///
+	/// `Buffer` also implements `std::io::Read`, which has its own `chain`; if both traits
+	/// are in scope, call this as `Buf::chain(a, b)` to disambiguate.
+	fn chain<U: Buf>(self, other: U) -> Chain<Self, U> { Chain::new(self, other) }
+
+	/// Caps how many bytes can still be read from this view
+	///
+	/// `Buffer` also implements `std::io::Read`, which has its own `take`; if both traits
+	/// are in scope, call this as `Buf::take(buf, limit)` to disambiguate.
+	fn take(self, limit: usize) -> Take<Self> { Take::new(self, limit) }
+}
+
+impl Buf for Buffer {
+	fn readable_bytes(&self) -> usize { Buffer::readable_bytes(self) }
+	fn read_u8(&mut self) -> u8 { Buffer::read_uint8(self) }
+	fn retrieve(&mut self, len: usize) { Buffer::retrieve(self, len) }
+
+	fn read_int8(&mut self) -> i8 { Buffer::read_int8(self) }
+	fn read_int16(&mut self) -> i16 { Buffer::read_int16(self) }
+	fn read_int32(&mut self) -> i32 { Buffer::read_int32(self) }
+	fn read_int64(&mut self) -> i64 { Buffer::read_int64(self) }
+	fn retrieve_as_string(&mut self, len: usize) -> String { Buffer::retrieve_as_string(self, len) }
+}
+
+/// A view logically concatenating the readable bytes of two buffers, without copying them
+/// into one allocation
+pub struct Chain<T, U> {
+	first: T,
+	second: U,
+}
+
+impl<T: Buf, U: Buf> Chain<T, U> {
+	fn new(first: T, second: U) -> Chain<T, U> { Chain { first: first, second: second } }
+}
+
+impl<T: Buf, U: Buf> Buf for Chain<T, U> {
+	fn readable_bytes(&self) -> usize { self.first.readable_bytes() + self.second.readable_bytes() }
+
+	fn read_u8(&mut self) -> u8 {
+		if self.first.readable_bytes() > 0 {
+			self.first.read_u8()
+		} else {
+			self.second.read_u8()
+		}
+	}
+
+	fn retrieve(&mut self, len: usize) {
+		let from_first = cmp::min(len, self.first.readable_bytes());
+		self.first.retrieve(from_first);
+		self.second.retrieve(len - from_first);
+	}
+}
+
+/// A view whose `readable_bytes()` is capped at a fixed limit, so a sub-parser can never
+/// read past it
+pub struct Take<T> {
+	inner: T,
+	limit: usize,
+}
+
+impl<T: Buf> Take<T> {
+	fn new(inner: T, limit: usize) -> Take<T> { Take { inner: inner, limit: limit } }
+}
+
+impl<T: Buf> Buf for Take<T> {
+	fn readable_bytes(&self) -> usize { cmp::min(self.limit, self.inner.readable_bytes()) }
+
+	fn read_u8(&mut self) -> u8 {
+		assert!(self.limit >= 1, "take limit exceeded");
+		self.limit -= 1;
+		self.inner.read_u8()
+	}
+
+	fn retrieve(&mut self, len: usize) {
+		assert!(len <= self.limit, "take limit exceeded");
+		self.limit -= len;
+		self.inner.retrieve(len);
+	}
+
+	fn read_int8(&mut self) -> i8 {
+		assert!(1 <= self.limit, "take limit exceeded");
+		self.limit -= 1;
+		self.inner.read_int8()
+	}
+
+	fn read_int16(&mut self) -> i16 {
+		assert!(2 <= self.limit, "take limit exceeded");
+		self.limit -= 2;
+		self.inner.read_int16()
+	}
+
+	fn read_int32(&mut self) -> i32 {
+		assert!(4 <= self.limit, "take limit exceeded");
+		self.limit -= 4;
+		self.inner.read_int32()
+	}
+
+	fn read_int64(&mut self) -> i64 {
+		assert!(8 <= self.limit, "take limit exceeded");
+		self.limit -= 8;
+		self.inner.read_int64()
+	}
+
+	fn retrieve_as_string(&mut self, len: usize) -> String {
+		assert!(len <= self.limit, "take limit exceeded");
+		self.limit -= len;
+		self.inner.retrieve_as_string(len)
+	}
 }
 
 mod tests {
@@ -532,7 +1253,25 @@ mod tests {
 		assert_eq!(buf.read_int32(), -1);
 		assert_eq!(buf.read_int16(), -1);
 	}
-	
+
+	#[test]
+	fn test_buffer_read_uint_float() {
+		let mut buf: Buffer = Buffer::new(None);
+		buf.append_uint8(255);
+		buf.append_uint16(65535);
+		buf.append_uint32(4294967295);
+		buf.append_uint64(18446744073709551615);
+		buf.append_float32(1.5f32);
+		buf.append_float64(2.5f64);
+		assert_eq!(buf.read_uint8(), 255);
+		assert_eq!(buf.read_uint16(), 65535);
+		assert_eq!(buf.read_uint32(), 4294967295);
+		assert_eq!(buf.read_uint64(), 18446744073709551615);
+		assert_eq!(buf.read_float32(), 1.5f32);
+		assert_eq!(buf.read_float64(), 2.5f64);
+		assert_eq!(buf.readable_bytes(), 0);
+	}
+
 	#[test]
 	fn test_buffer_find_eol() {
 		let mut string = String::new();
@@ -556,4 +1295,102 @@ mod tests {
 		assert_eq!(buf.find_crlf(), None);
 //		assert_eq!(buf.find_crlf_from(90_000), None);
 	}
+
+	#[test]
+	fn test_buffer_read_write_io() {
+		use std::io::{Read as IoRead, Write as IoWrite};
+
+		let mut buf: Buffer = Buffer::new(None);
+		let written = buf.write(b"HTTP").unwrap();
+		assert_eq!(written, 4);
+		assert_eq!(buf.readable_bytes(), 4);
+
+		let mut out = [0u8; 4];
+		let read = buf.read(&mut out).unwrap();
+		assert_eq!(read, 4);
+		assert_eq!(&out, b"HTTP");
+		assert_eq!(buf.readable_bytes(), 0);
+	}
+
+	#[test]
+	fn test_buffer_seek_and_peek_at() {
+		use std::io::SeekFrom;
+
+		let mut buf: Buffer = Buffer::new(None);
+		buf.append_int32(1);
+		buf.append_int32(2);
+		buf.append_int32(3);
+		assert_eq!(buf.tell(), 0);
+
+		assert_eq!(buf.peek_int32_at(4), 2);
+		assert_eq!(buf.tell(), 0);
+
+		assert_eq!(buf.seek(SeekFrom::Current(4)).unwrap(), 4);
+		assert_eq!(buf.tell(), 4);
+		assert_eq!(buf.read_int32(), 2);
+
+		assert_eq!(buf.seek(SeekFrom::Start(0)).unwrap(), 0);
+		assert_eq!(buf.read_int32(), 1);
+
+		assert_eq!(buf.seek(SeekFrom::End(0)).unwrap(), 12);
+		assert!(buf.seek(SeekFrom::Current(1)).is_err());
+		assert!(buf.seek(SeekFrom::Start(100)).is_err());
+	}
+
+	#[test]
+	fn test_buffer_try_accessors() {
+		let mut buf: Buffer = Buffer::new(None);
+		assert!(buf.try_read_int32().is_err());
+		assert!(buf.try_peek_int64().is_err());
+
+		buf.append_int32(42);
+		assert!(buf.try_peek_int64().is_err());
+		assert_eq!(buf.try_read_int32().unwrap(), 42);
+		assert_eq!(buf.readable_bytes(), 0);
+
+		buf.append_bytes(&[0xffu8, 0xfeu8]);
+		match buf.try_retrieve_as_string(2) {
+			Err(BufferError::Utf8(_)) => (),
+			other => panic!("expected a Utf8 error, got {:?}", other),
+		}
+
+		buf.append_string(&"hi".to_string());
+		assert!(buf.try_retrieve(10).is_err());
+		assert!(buf.try_retrieve(2).is_ok());
+	}
+
+	#[test]
+	fn test_buffer_chain() {
+		let mut head: Buffer = Buffer::new(None);
+		head.append_int16(0x0102);
+		let mut tail: Buffer = Buffer::new(None);
+		tail.append_int16(0x0304);
+		tail.append_string(&"hi".to_string());
+
+		let mut chained = Buf::chain(head, tail);
+		assert_eq!(chained.readable_bytes(), 6);
+		assert_eq!(chained.read_int32(), 0x01020304);
+		assert_eq!(chained.retrieve_as_string(2), "hi".to_string());
+		assert_eq!(chained.readable_bytes(), 0);
+	}
+
+	#[test]
+	fn test_buffer_take() {
+		let mut buf: Buffer = Buffer::new(None);
+		buf.append_string(&"HELLO".to_string());
+
+		let mut limited = Buf::take(buf, 2);
+		assert_eq!(limited.readable_bytes(), 2);
+		assert_eq!(limited.retrieve_as_string(2), "HE".to_string());
+		assert_eq!(limited.readable_bytes(), 0);
+	}
+
+	#[test]
+	fn test_buffer_bytes_iterator() {
+		let mut buf: Buffer = Buffer::new(None);
+		buf.append_string(&"HI".to_string());
+		let collected: Vec<u8> = buf.byte_iter().collect();
+		assert_eq!(collected, vec!['H' as u8, 'I' as u8]);
+		assert_eq!(buf.readable_bytes(), 0);
+	}
 }
\ No newline at end of file